@@ -1,5 +1,5 @@
 import { describe, it, expect, beforeEach } from 'vitest';
-import { parseTarget, createInitialRows, setWordRow, latestWords } from '../components/monitor';
+import { parseTarget, formatDeviceAddr, resolveTarget, createInitialRows, setWordRow, latestWords } from '../components/monitor';
 
 describe('monitor component basic behaviors', () => {
   beforeEach(() => {
@@ -15,6 +15,35 @@ describe('monitor component basic behaviors', () => {
     expect(parseTarget('WFF')).toEqual({ key: 'W', addr: 0xFF });
   });
 
+  it('parseTarget uses the device radix (W/X hex, D/M decimal)', () => {
+    expect(parseTarget('W16')).toEqual({ key: 'W', addr: 0x16 });
+    expect(parseTarget('D16')).toEqual({ key: 'D', addr: 16 });
+    expect(parseTarget('X1F')).toEqual({ key: 'X', addr: 0x1F });
+    expect(parseTarget('SWA0')).toEqual({ key: 'SW', addr: 0xA0 });
+    expect(parseTarget('M100')).toEqual({ key: 'M', addr: 100 });
+    expect(parseTarget('D1A')).toBeNull();
+  });
+
+  it('formatDeviceAddr round-trips with parseTarget', () => {
+    expect(formatDeviceAddr('W', 0x16)).toBe('W16');
+    expect(formatDeviceAddr('D', 16)).toBe('D16');
+    for (const s of ['W1FFF', 'D100', 'X10', 'ZR12']) {
+      const p = parseTarget(s)!;
+      expect(formatDeviceAddr(p.key, p.addr)).toBe(s);
+    }
+  });
+
+  it('resolveTarget keeps the previous target on invalid input', () => {
+    const prev = { key: 'D', addr: 100 };
+    expect(resolveTarget('W1F', prev)).toEqual({ target: { key: 'W', addr: 0x1F }, error: null });
+    for (const bad of ['D1A', 'M1F', '', null]) {
+      const r = resolveTarget(bad, prev);
+      // never a guessed key like 'DA' at address 0
+      expect(r.target).toBe(prev);
+      expect(r.error).toContain('keeping D100');
+    }
+  });
+
   it('createInitialRows makes 30 rows', () => {
     const container = document.createElement('table');
     const tbody = document.createElement('tbody');
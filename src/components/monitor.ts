@@ -42,6 +42,16 @@ function uiLog(msg: string) {
   } catch (e) { try { console.log('[MON]', msg, e); } catch(_) {} }
 }
 
+// Devices addressed in hexadecimal per GX Works conventions; all others are decimal (D, M, T, ZR, ...)
+const HEX_DEVICES = ['X', 'Y', 'B', 'W', 'SB', 'SW', 'DX', 'DY'];
+
+export function isHexDevice(key: string) { return HEX_DEVICES.includes(key.toUpperCase()); }
+
+// format a device address the way GX Works displays it (e.g. W1F, D100)
+export function formatDeviceAddr(key: string, addr: number) {
+  return isHexDevice(key) ? `${key}${addr.toString(16).toUpperCase()}` : `${key}${addr}`;
+}
+
 export function parseTarget(s: string | null) {
   if (!s) return null;
   const up = s.toUpperCase().trim();
@@ -49,18 +59,35 @@ export function parseTarget(s: string | null) {
   if (i === 0) return null;
   let key = up.slice(0, i);
   let numPart = up.slice(i).trim();
-  // handle cases like 'WFF' where the address is hex letters following a single-letter device code
-  if (!numPart && key.length > 1) {
-    numPart = key.slice(1);
-    key = key[0];
+  // handle cases like 'WFF' or 'SWA0' where hex address letters follow a hex-addressed device code
+  if (!isHexDevice(key)) {
+    const hexKey = HEX_DEVICES
+      .filter((d) => key.startsWith(d) && /^[A-F]+$/.test(key.slice(d.length)))
+      .sort((a, b) => b.length - a.length)[0];
+    if (hexKey) {
+      numPart = key.slice(hexKey.length) + numPart;
+      key = hexKey;
+    }
   }
   if (!numPart) return null;
-  const isHex = /[A-F]/i.test(numPart);
-  const addr = isHex ? parseInt(numPart, 16) : parseInt(numPart, 10);
+  // radix is decided by the device, not by the digits (W16 is 0x16, D16 is 16)
+  const hex = isHexDevice(key);
+  if (!(hex ? /^[0-9A-F]+$/ : /^[0-9]+$/).test(numPart)) return null;
+  const addr = parseInt(numPart, hex ? 16 : 10);
   if (Number.isNaN(addr)) return null;
   return { key, addr };
 }
 
+export type MonitorTarget = { key: string; addr: number };
+
+// resolve a monitor target input; invalid input keeps `previous` and reports why,
+// rather than guessing a device key from the letters (e.g. 'D1A' must not become 'DA')
+export function resolveTarget(raw: string | null, previous: MonitorTarget): { target: MonitorTarget; error: string | null } {
+  const parsed = parseTarget(raw);
+  if (parsed) return { target: parsed, error: null };
+  return { target: previous, error: `invalid device "${raw || ''}"; keeping ${formatDeviceAddr(previous.key, previous.addr)}` };
+}
+
 export function createInitialRows(key: string, addr: number, count: number) {
   for (let i = 0; i < count; i++) setWordRow(key, addr + i, 0);
 }
@@ -93,7 +120,7 @@ function renderRowForWord(key: string, addr: number, word: number) {
       tr.id = rowId;
       const tdLabel = document.createElement('td');
       tdLabel.className = 'device-label';
-      tdLabel.textContent = `${key}${addr}`;
+      tdLabel.textContent = formatDeviceAddr(key, addr);
       tr.appendChild(tdLabel);
       for (let b = 15; b >= 0; b--) {
         const td = document.createElement('td');
@@ -162,7 +189,7 @@ function renderRowForWord(key: string, addr: number, word: number) {
 let eventApiAvailable = false; let monitorFallbackId: any = null;
 export async function startFallbackPolling(key: string, addr: number, intervalMs: number) {
   stopFallbackPolling(); const count = 30;
-  uiLog(`startFallbackPolling ${key}${addr} interval=${intervalMs}`);
+  uiLog(`startFallbackPolling ${formatDeviceAddr(key, addr)} interval=${intervalMs}`);
   monitorFallbackId = setInterval(async () => {
     try {
       const vals = await invoke('get_words', { key: key, addr: addr, count: count });
@@ -212,8 +239,8 @@ export async function initEventListeners() {
             try {
               const rawEl = document.getElementById('mon-target') as HTMLInputElement | null;
               const raw = rawEl ? (rawEl.value || 'D') : 'D';
-              let parsed: any = parseTarget(raw.toString().trim().toUpperCase());
-              if (!parsed) parsed = { key: raw.replace(/[^A-Z]/g, ''), addr: 0 } as any;
+              // invalid input selects nothing rather than a guessed device
+              const parsed = parseTarget(raw.toString().trim().toUpperCase());
               if (parsed) try { selectRow(parsed.key, parsed.addr); } catch (e) {}
             } catch (e) {}
           }
@@ -7,9 +7,12 @@ use melsec_mc_mock::MockServer;
 use serde::Serialize;
 use std::io::Write;
 use tauri::Emitter;
-use tokio::sync::{Mutex as AsyncMutex, RwLock};
+use tokio::sync::{watch, Mutex as AsyncMutex, RwLock};
 use tracing::{debug, info};
 
+// number of words read and emitted per monitor poll
+const MONITOR_COUNT: usize = 30;
+
 #[derive(Clone, Serialize)]
 struct MonitorPayload {
     key: String,
@@ -17,14 +20,29 @@ struct MonitorPayload {
     vals: Vec<u16>,
 }
 
+#[derive(Clone, Serialize)]
+struct MonitorStatus {
+    key: String,
+    addr: usize,
+    count: usize,
+    interval_ms: u64,
+    // seq counts emits since start_monitor (0 = nothing emitted yet)
+    seq: u64,
+    vals: Vec<u16>,
+}
+
 struct AppState {
     rt: tokio::runtime::Runtime,
     server: Arc<RwLock<MockServer>>,
     monitor_handle: Arc<AsyncMutex<Option<tokio::task::JoinHandle<()>>>>,
     // handles for spawned TCP/UDP listener tasks so they can be aborted by stop_mock
     listener_handles: Arc<AsyncMutex<Vec<tokio::task::JoinHandle<()>>>>,
-    // monitor_cfg: (device_key_symbol, addr, interval_ms) - count is fixed to 30
+    // monitor_cfg: (device_key_symbol, addr, interval_ms) - count is fixed to MONITOR_COUNT
     monitor_cfg: Arc<Mutex<Option<(String, usize, u64)>>>,
+    // sender used by set_monitor_interval to retune the running monitor loop
+    monitor_interval_tx: Arc<Mutex<Option<watch::Sender<u64>>>>,
+    // monitor_last: (seq, vals) of the most recent monitor emit, reported by list_monitors
+    monitor_last: Arc<Mutex<Option<(u64, Vec<u16>)>>>,
 }
 
 impl AppState {
@@ -37,11 +55,22 @@ impl AppState {
             monitor_handle: Arc::new(AsyncMutex::new(None)),
             listener_handles: Arc::new(AsyncMutex::new(Vec::new())),
             monitor_cfg: Arc::new(Mutex::new(None)),
+            monitor_interval_tx: Arc::new(Mutex::new(None)),
+            monitor_last: Arc::new(Mutex::new(None)),
         }
     }
 }
 
-// Start internal mock server: bind TCP and optional UDP
+// remember the values of a monitor emit and bump its sequence number
+fn record_monitor_emit(last: &Mutex<Option<(u64, Vec<u16>)>>, vals: &[u16]) {
+    let mut guard = last.lock().unwrap();
+    let seq = guard.as_ref().map_or(1, |(seq, _)| seq + 1);
+    *guard = Some((seq, vals.to_vec()));
+}
+
+// Start internal mock server: bind TCP and optional UDP.
+// Does not start a monitor; callers must invoke start_monitor again after a
+// stop_mock -> start_mock cycle.
 #[tauri::command]
 fn start_mock(
     state: tauri::State<'_, Arc<AppState>>,
@@ -84,6 +113,8 @@ fn start_mock(
     Ok(())
 }
 
+// Stop listeners and clear the store. Any running monitor is stopped first and
+// is not resumed by a later start_mock.
 #[tauri::command]
 async fn stop_mock(
     window: tauri::Window,
@@ -95,6 +126,9 @@ async fn stop_mock(
     for h in hs.drain(..) {
         h.abort();
     }
+    // stop the monitor before clearing the store so it never emits zero payloads
+    // for the wiped memory
+    halt_monitor(app).await;
     // clear the mock server internal DeviceMap to reset memory
     {
         let srv = app.server.clone();
@@ -110,6 +144,7 @@ async fn stop_mock(
     Ok(())
 }
 
+// `addr` is numeric; radix per device (W/X hex, D/M decimal) is resolved by the frontend
 #[tauri::command]
 fn set_words(
     window: tauri::Window,
@@ -121,6 +156,7 @@ fn set_words(
     let app = state.inner();
     let server = app.server.clone();
     let monitor_cfg = app.monitor_cfg.clone();
+    let monitor_last = app.monitor_last.clone();
     // log invocation and persist debug trace to cwd/tauri_debug.log
     debug!(
         "[TAURI BACKEND] set_words called key={} addr={} words={:?}",
@@ -185,9 +221,7 @@ fn set_words(
         // push immediate monitor if configured
         let monitor_snapshot = { monitor_cfg.lock().unwrap().clone() };
         if let Some((mkey, maddr, _interval)) = monitor_snapshot {
-            // fixed monitor count of 30
-            let mcount = 30usize;
-            let v = s.get_words(&mkey, maddr, mcount).await;
+            let v = s.get_words(&mkey, maddr, MONITOR_COUNT).await;
             debug!(
                 "[TAURI BACKEND] set_words trigger monitor emit key={} addr={} vals={:?}",
                 mkey, maddr, v
@@ -212,6 +246,7 @@ fn set_words(
                     );
                 }
             }
+            record_monitor_emit(&monitor_last, &v);
             let payload = MonitorPayload {
                 key: mkey.clone(),
                 addr: maddr,
@@ -291,63 +326,172 @@ async fn start_monitor(
     interval_ms: u64,
 ) -> Result<(), String> {
     // target is combined like "D100" or "W1FFF"; parsing uses device base
+    check_monitor_interval(interval_ms)?;
     let app = state.inner();
     let server = app.server.clone();
     // parse target using crate device parser
     let (device, addr_u32) =
         parse_device_and_address(&target).map_err(|e| format!("parse target error: {}", e))?;
     let addr = addr_u32 as usize;
-    // fixed count = 30
-    let count = 30usize;
+    let count = MONITOR_COUNT;
     let win = window.clone();
+    // abort the previous monitor and wait for it to finish so it cannot emit or
+    // record values for the old target after the new one is installed
+    let mut handle_guard = app.monitor_handle.lock().await;
+    if let Some(old) = handle_guard.take() {
+        old.abort();
+        let _ = old.await;
+    }
     // notify frontend that monitor started
     let _ = win.emit("server-status", "監視中");
     let key = device.symbol_str().to_string();
-    // store cfg (store the device symbol key, not the raw target string)
-    *app.monitor_cfg.lock().unwrap() = Some((key.clone(), addr, interval_ms));
-    let h = app.rt.spawn(async move {
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(interval_ms));
-        // Do an immediate first poll so frontend shows initial state without waiting
-        {
-            let s = server.read().await;
-            // use symbol `key` with explicit addr so DeviceMap resolves correctly
-            let v = s.get_words(&key, addr, count).await;
-            let payload = MonitorPayload {
-                key: key.clone(),
-                addr,
-                vals: v,
-            };
-            let _ = win.emit("monitor", payload.clone());
-        }
-        loop {
-            interval.tick().await;
+    let interval_rx = arm_monitor(app, key.clone(), addr, interval_ms);
+    let monitor_last = app.monitor_last.clone();
+    let poll = move || {
+        let server = server.clone();
+        let key = key.clone();
+        let win = win.clone();
+        let monitor_last = monitor_last.clone();
+        async move {
             let s = server.read().await;
             // use symbol `key` with explicit addr so DeviceMap resolves correctly
             let v = s.get_words(&key, addr, count).await;
+            record_monitor_emit(&monitor_last, &v);
             // emit monitor payload to frontend (no console logging)
             let payload = MonitorPayload {
                 key: key.clone(),
                 addr,
                 vals: v,
             };
-            let _ = win.emit("monitor", payload.clone());
+            let _ = win.emit("monitor", payload);
         }
-    });
-    *app.monitor_handle.lock().await = Some(h);
+    };
+    // the interval's first tick fires immediately, so the frontend shows the
+    // initial state without waiting
+    let h = app
+        .rt
+        .spawn(run_monitor_loop(interval_ms, interval_rx, poll));
+    *handle_guard = Some(h);
     Ok(())
 }
 
+// Record the monitor config and install a fresh interval channel. Replacing the
+// sender closes the previous monitor's receiver, and the last emitted values are
+// reset so list_monitors never reports the previous target's data.
+fn arm_monitor(app: &AppState, key: String, addr: usize, interval_ms: u64) -> watch::Receiver<u64> {
+    // store cfg (store the device symbol key, not the raw target string)
+    *app.monitor_cfg.lock().unwrap() = Some((key, addr, interval_ms));
+    let (interval_tx, interval_rx) = watch::channel(interval_ms);
+    *app.monitor_interval_tx.lock().unwrap() = Some(interval_tx);
+    *app.monitor_last.lock().unwrap() = None;
+    interval_rx
+}
+
+// tokio::time::interval panics on a zero period, so reject it up front
+fn check_monitor_interval(ms: u64) -> Result<(), String> {
+    if ms == 0 {
+        return Err("monitor interval must be greater than 0".to_string());
+    }
+    Ok(())
+}
+
+// Call `poll` every `interval_ms`, switching cadence whenever a new interval is
+// sent on `interval_rx`. Returns once the sender is dropped.
+async fn run_monitor_loop<F, Fut>(
+    interval_ms: u64,
+    mut interval_rx: watch::Receiver<u64>,
+    mut poll: F,
+) where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(interval_ms));
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            changed = interval_rx.changed() => {
+                if changed.is_err() {
+                    // sender dropped: this monitor was stopped or replaced
+                    break;
+                }
+                // recreate the interval in place so the loop keeps its state
+                let ms = *interval_rx.borrow_and_update();
+                interval = tokio::time::interval(tokio::time::Duration::from_millis(ms));
+                // consume the immediate first tick of the new interval
+                interval.tick().await;
+                continue;
+            }
+        }
+        poll().await;
+    }
+}
+
 #[tauri::command]
 async fn stop_monitor(state: tauri::State<'_, Arc<AppState>>) -> Result<(), String> {
-    let app = state.inner();
-    let mut guard = app.monitor_handle.lock().await;
-    if let Some(h) = guard.take() {
+    halt_monitor(state.inner()).await;
+    Ok(())
+}
+
+// Abort the monitor task, wait until it has finished and clear its state.
+// Returns whether a monitor was running.
+async fn halt_monitor(app: &AppState) -> bool {
+    let running = app.monitor_handle.lock().await.take();
+    let was_running = running.is_some();
+    if let Some(h) = running {
         h.abort();
+        let _ = h.await;
     }
     *app.monitor_cfg.lock().unwrap() = None;
+    *app.monitor_interval_tx.lock().unwrap() = None;
+    *app.monitor_last.lock().unwrap() = None;
+    was_running
+}
+
+// Change the running monitor's poll interval without restarting it
+#[tauri::command]
+fn set_monitor_interval(state: tauri::State<'_, Arc<AppState>>, ms: u64) -> Result<(), String> {
+    check_monitor_interval(ms)?;
+    let app = state.inner();
+    let guard = app.monitor_interval_tx.lock().unwrap();
+    let tx = guard
+        .as_ref()
+        .ok_or_else(|| "monitor is not running".to_string())?;
+    tx.send(ms)
+        .map_err(|e| format!("set monitor interval error: {}", e))?;
+    // keep stored cfg in sync so set_words and later queries see the new interval
+    if let Some(cfg) = app.monitor_cfg.lock().unwrap().as_mut() {
+        cfg.2 = ms;
+    }
+    info!("[TAURI BACKEND] monitor interval changed to {}ms", ms);
     Ok(())
 }
 
+// List active monitors with their config and last emitted values
+#[tauri::command]
+fn list_monitors(state: tauri::State<'_, Arc<AppState>>) -> Result<Vec<MonitorStatus>, String> {
+    Ok(monitor_statuses(state.inner()))
+}
+
+// Only a single monitor is supported (start_monitor replaces the running one),
+// so this returns at most one entry.
+fn monitor_statuses(app: &AppState) -> Vec<MonitorStatus> {
+    let cfg = app.monitor_cfg.lock().unwrap().clone();
+    let last = app.monitor_last.lock().unwrap().clone();
+    cfg.map(|(key, addr, interval_ms)| {
+        let (seq, vals) = last.unwrap_or_default();
+        MonitorStatus {
+            key,
+            addr,
+            count: MONITOR_COUNT,
+            interval_ms,
+            seq,
+            vals,
+        }
+    })
+    .into_iter()
+    .collect()
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let state = Arc::new(AppState::new());
@@ -366,7 +510,141 @@ pub fn run() {
             get_words,
             start_monitor,
             stop_monitor,
+            set_monitor_interval,
+            list_monitors,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::time::{advance, Duration};
+
+    // let spawned tasks run until they block on the paused clock
+    async fn settle() {
+        for _ in 0..16 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn monitor_loop_follows_interval_change() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        let (tx, rx) = watch::channel(100u64);
+        let h = tokio::spawn(run_monitor_loop(100, rx, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {}
+        }));
+        settle().await;
+        // the first tick of an interval completes immediately
+        assert_eq!(polls.load(Ordering::SeqCst), 1);
+        advance(Duration::from_millis(300)).await;
+        settle().await;
+        assert_eq!(polls.load(Ordering::SeqCst), 4);
+
+        tx.send(500).unwrap();
+        settle().await;
+        // the retuned interval's immediate tick is consumed without polling
+        assert_eq!(polls.load(Ordering::SeqCst), 4);
+        advance(Duration::from_millis(400)).await;
+        settle().await;
+        assert_eq!(polls.load(Ordering::SeqCst), 4);
+        advance(Duration::from_millis(100)).await;
+        settle().await;
+        assert_eq!(polls.load(Ordering::SeqCst), 5);
+
+        drop(tx);
+        settle().await;
+        assert!(h.is_finished());
+    }
+
+    #[test]
+    fn zero_monitor_interval_is_rejected() {
+        assert!(check_monitor_interval(0).is_err());
+        assert!(check_monitor_interval(1).is_ok());
+    }
+
+    #[test]
+    fn halt_monitor_stops_emits_and_clears_cfg() {
+        let app = AppState::new();
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = polls.clone();
+        app.rt.block_on(async {
+            let rx = arm_monitor(&app, "D".to_string(), 0, 5);
+            let h = app.rt.spawn(run_monitor_loop(5, rx, move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async {}
+            }));
+            *app.monitor_handle.lock().await = Some(h);
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            assert!(polls.load(Ordering::SeqCst) > 0);
+
+            // stop_mock halts the monitor before clearing the store
+            assert!(halt_monitor(&app).await);
+            let after_halt = polls.load(Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            // no further polls, so no zero payloads for the wiped store
+            assert_eq!(polls.load(Ordering::SeqCst), after_halt);
+            assert!(app.monitor_cfg.lock().unwrap().is_none());
+            assert!(app.monitor_interval_tx.lock().unwrap().is_none());
+            assert!(app.monitor_handle.lock().await.is_none());
+            // a second stop is a no-op
+            assert!(!halt_monitor(&app).await);
+        });
+    }
+
+    #[test]
+    fn list_monitors_reports_latest_of_two_monitors() {
+        let app = AppState::new();
+        assert!(monitor_statuses(&app).is_empty());
+
+        // first monitor on D0 emits twice
+        let first_rx = arm_monitor(&app, "D".to_string(), 0, 500);
+        record_monitor_emit(&app.monitor_last, &[1, 2]);
+        record_monitor_emit(&app.monitor_last, &[3, 4]);
+        let first = monitor_statuses(&app);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].key, "D");
+        assert_eq!(first[0].count, MONITOR_COUNT);
+        assert_eq!(first[0].seq, 2);
+        assert_eq!(first[0].vals, vec![3, 4]);
+
+        // starting a second monitor replaces the first and drops its values
+        let _second_rx = arm_monitor(&app, "W".to_string(), 0x10, 250);
+        assert!(
+            first_rx.has_changed().is_err(),
+            "first monitor channel closed"
+        );
+        let armed = monitor_statuses(&app);
+        assert_eq!(armed.len(), 1);
+        assert_eq!(armed[0].key, "W");
+        assert_eq!(armed[0].seq, 0);
+        assert!(armed[0].vals.is_empty());
+
+        record_monitor_emit(&app.monitor_last, &[7]);
+        let second = monitor_statuses(&app);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].addr, 0x10);
+        assert_eq!(second[0].interval_ms, 250);
+        assert_eq!(second[0].seq, 1);
+        assert_eq!(second[0].vals, vec![7]);
+    }
+
+    #[test]
+    fn monitor_target_radix_matches_gui() {
+        // the GUI sends hex-addressed devices in hex (W1F) and decimal ones in decimal (D16)
+        let (w, w_addr) = parse_device_and_address("W1F").unwrap();
+        assert_eq!(w.symbol_str(), "W");
+        assert_eq!(w_addr, 0x1F);
+        let (x, x_addr) = parse_device_and_address("X10").unwrap();
+        assert_eq!(x.symbol_str(), "X");
+        assert_eq!(x_addr, 0x10);
+        let (d, d_addr) = parse_device_and_address("D16").unwrap();
+        assert_eq!(d.symbol_str(), "D");
+        assert_eq!(d_addr, 16);
+    }
+}
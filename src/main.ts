@@ -8,7 +8,7 @@ declare const window: any;
 declare global { interface Window { __TAURI__?: any } }
 const { invoke } = (window as any).__TAURI__.core as any;
 
-import { getCurrentFormat, setCurrentFormat, parseTarget, createInitialRows, startFallbackPolling, stopFallbackPolling, selectRow, setWordRow, isEventApiAvailable, initEventListeners } from './components/monitor';
+import { getCurrentFormat, setCurrentFormat, resolveTarget, type MonitorTarget, formatDeviceAddr, createInitialRows, startFallbackPolling, stopFallbackPolling, selectRow, setWordRow, isEventApiAvailable, initEventListeners } from './components/monitor';
 
 const els: { [k: string]: HTMLElement | HTMLInputElement | null } = {} as any;
 
@@ -44,7 +44,8 @@ async function startMock(tcpPort: number, udpPort: number, timAwaitMs: number) {
 }
 
 async function startMonitorForTarget(targetKey: string, addr: number) {
-  const backendTarget = `${targetKey}${addr}`;
+  // format with the device's radix so the backend parser sees e.g. W1F rather than W31
+  const backendTarget = formatDeviceAddr(targetKey, addr);
   const interval_ms = 500; // fixed internally per spec
   try {
     await invoke('start_monitor', { target: backendTarget, intervalMs: interval_ms });
@@ -88,6 +89,16 @@ window.addEventListener('DOMContentLoaded', () => {
     els[id] = document.getElementById(id) as any;
   });
 
+  // last valid monitor target; kept (and the error logged) when the input cannot be parsed
+  let monTarget: MonitorTarget = { key: 'D', addr: 0 };
+  function readMonTarget(): { target: MonitorTarget; ok: boolean } {
+    const raw = ((els['mon-target'] as HTMLInputElement).value || '').toString().trim().toUpperCase();
+    const { target, error } = resolveTarget(raw, monTarget);
+    if (error) logMonitor(`[TS] ${error}`);
+    monTarget = target;
+    return { target, ok: !error };
+  }
+
   // mock toggle behaviour: Start Mock <-> Stop Mock
   let mockRunning = false;
   const mockBtn = els['mock-toggle'] as HTMLButtonElement;
@@ -111,9 +122,7 @@ window.addEventListener('DOMContentLoaded', () => {
           }
         } catch (e) {}
         // start monitor for current target (30 items)
-  const rawTarget = ((els['mon-target'] as HTMLInputElement).value || 'D').toString().trim().toUpperCase();
-  let parsed: any = parseTarget(rawTarget);
-  if (!parsed) parsed = { key: rawTarget.replace(/[^A-Z]/g, ''), addr: 0 } as any;
+        const parsed = readMonTarget().target;
         try { createInitialRows(parsed.key, parsed.addr, 30); } catch (e) {}
         await startMonitorForTarget(parsed.key, parsed.addr);
   if (!isEventApiAvailable()) startFallbackPolling(parsed.key, parsed.addr, 500);
@@ -157,9 +166,9 @@ window.addEventListener('DOMContentLoaded', () => {
   if (monTargetEl) {
     monTargetEl.addEventListener('keydown', async (e) => {
       if (e.key === 'Enter') {
-        const raw = (monTargetEl.value || '').toString().trim().toUpperCase();
-        let parsed: any = parseTarget(raw);
-        if (!parsed) parsed = { key: raw.replace(/[^A-Z]/g, ''), addr: 0 } as any;
+        const { target: parsed, ok } = readMonTarget();
+        // reject invalid input: keep the current rows and monitor
+        if (!ok) return;
         try { createInitialRows(parsed.key, parsed.addr, 30); } catch (err) {}
         // if mock is running, restart monitor for new target
         if (mockRunning) {
@@ -238,7 +247,7 @@ window.addEventListener('DOMContentLoaded', () => {
       editTarget = { key: d.key, addr: d.addr };
       // always update popup title so it follows selection immediately when visible
       try {
-        if (editTitle) editTitle.textContent = `Write ${d.key}${d.addr}`;
+        if (editTitle) editTitle.textContent = `Write ${formatDeviceAddr(d.key, d.addr)}`;
         // if popup visible, clear value and focus input so user can type immediately
         if (editModal && editModal.style.display && editModal.style.display !== 'none') {
           if (editValue) { editValue.value = ''; try { editValue.focus(); } catch (e) {} }
@@ -249,7 +258,7 @@ window.addEventListener('DOMContentLoaded', () => {
 
   function showEditModal(key: string, addr: number) {
     editTarget = { key, addr };
-    if (editTitle) editTitle.textContent = `Write ${key}${addr}`;
+    if (editTitle) editTitle.textContent = `Write ${formatDeviceAddr(key, addr)}`;
     if (editValue) editValue.value = '';
   // set initial write-type to current monitor display format
   selectedWriteType = getCurrentFormat() || 'U16';
@@ -564,18 +573,6 @@ window.addEventListener('DOMContentLoaded', () => {
     });
   }
 
-  function parseTarget(s: string | null) {
-    if (!s) return null;
-    const up = s.toUpperCase().trim();
-    let i = 0; while (i < up.length && /[A-Z]/.test(up[i])) i++;
-    if (i === 0) return null;
-    const key = up.slice(0, i); const numPart = up.slice(i).trim(); if (!numPart) return null;
-    const isHex = /[A-F]/i.test(numPart);
-    const addr = isHex ? parseInt(numPart, 16) : parseInt(numPart, 10);
-    if (Number.isNaN(addr)) return null;
-    return { key, addr };
-  }
-
   function createInitialRows(key: string, addr: number, count: number) {
     for (let i = 0; i < count; i++) setWordRow(key, addr + i, 0);
   }
@@ -587,24 +584,23 @@ window.addEventListener('DOMContentLoaded', () => {
   // populate rows; if that fails, fall back to creating empty rows.
   (async () => {
     try {
-      const rawTarget = ((els['mon-target'] as HTMLInputElement).value || 'D').toString().trim().toUpperCase();
-      let parsed: any = parseTarget(rawTarget);
-      if (!parsed) parsed = { key: rawTarget.replace(/[^A-Z]/g, ''), addr: 0 } as any;
+      const parsed = readMonTarget().target;
+      const label = formatDeviceAddr(parsed.key, parsed.addr);
       const count = 30;
       try {
         const vals: number[] = await invoke('get_words', { key: parsed.key, addr: parsed.addr, count: count });
         if (Array.isArray(vals) && vals.length > 0) {
           for (let i = 0; i < vals.length; i++) setWordRow(parsed.key, parsed.addr + i, vals[i] & 0xffff);
           if (vals.length < count) createInitialRows(parsed.key, parsed.addr + vals.length, count - vals.length);
-          logMonitor(`[TS] initial get_words populated ${vals.length} rows for ${parsed.key}${parsed.addr}`);
+          logMonitor(`[TS] initial get_words populated ${vals.length} rows for ${label}`);
         } else {
           createInitialRows(parsed.key, parsed.addr, count);
-          logMonitor(`[TS] initial get_words returned empty; created ${count} empty rows for ${parsed.key}${parsed.addr}`);
+          logMonitor(`[TS] initial get_words returned empty; created ${count} empty rows for ${label}`);
         }
       } catch (e) {
         // backend might not be running yet; create empty rows so UI has something
         createInitialRows(parsed.key, parsed.addr, count);
-        logMonitor(`[TS] initial get_words failed; created ${count} empty rows for ${parsed.key}${parsed.addr}: ${e}`);
+        logMonitor(`[TS] initial get_words failed; created ${count} empty rows for ${label}: ${e}`);
       }
     } catch (e) { /* ignore */ }
 
@@ -623,10 +619,8 @@ window.addEventListener('DOMContentLoaded', () => {
       const isRunning = btn && btn.textContent && btn.textContent.includes('停止');
       if (!isEventApiAvailable() && isRunning) {
         try {
-          const raw = (els['mon-target'] as HTMLInputElement).value || 'D';
-          let parsed: any = parseTarget(raw);
-          if (!parsed) parsed = { key: raw.replace(/[^A-Z]/g, ''), addr: 0 } as any;
-          if (parsed) startFallbackPolling(parsed.key, parsed.addr, 500);
+          const parsed = readMonTarget().target;
+          startFallbackPolling(parsed.key, parsed.addr, 500);
         } catch (e) { console.warn('failed to start fallback polling', e); }
       } else if (!isEventApiAvailable() && !isRunning) stopFallbackPolling();
     }, 50);